@@ -7,11 +7,53 @@ fn main() {
     }
 
     impl Message {
-        fn call (&self) {
-           // Sexy function body
+        fn call(&self) -> String {
+            match self {
+                Message::Quit => String::from("shutting down"),
+                Message::Move { x, y } => format!("moving to ({}, {})", x, y),
+                Message::Write(text) => format!("writing: {}", text),
+                Message::ChangeColor(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+            }
         }
     }
 
     let message = Message::Write(String::from("Rust enums"));
-    message.call();
+    println!("{}", message.call());
+
+    let message = Message::Move { x: 10, y: 20 };
+    println!("{}", message.call());
+
+    let message = Message::ChangeColor(255, 0, 128);
+    println!("{}", message.call());
+
+    let message = Message::Quit;
+    println!("{}", message.call());
+
+    enum Coin {
+        Penny,
+        Nickel,
+        Dime,
+        Quarter,
+    }
+
+    fn value_in_cents(coin: Coin) -> u32 {
+        match coin {
+            Coin::Penny => {
+                println!("Lucky penny!");
+                1
+            }
+            Coin::Nickel => 5,
+            Coin::Dime => 10,
+            Coin::Quarter => 25,
+        }
+    }
+
+    println!("a penny is worth {}", value_in_cents(Coin::Penny));
+    println!("a quarter is worth {}", value_in_cents(Coin::Quarter));
+
+    let some_u8_value: Option<u8> = Some(3);
+    match some_u8_value {
+        Some(n) => println!("got a value: {}", n),
+        _ => (),
+    }
 }
@@ -13,17 +13,33 @@ impl Rectangle {
     fn can_hold(&self, other: &Rectangle) -> bool {
         self.width > other.width && self.height > other.height
     }
+
+    fn square(size: u32) -> Self {
+        Rectangle {
+            width: size,
+            height: size,
+        }
+    }
+}
+
+impl std::fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}x{} (area {})", self.width, self.height, self.area())
+    }
 }
 
 fn main() {
     let rect_one = Rectangle { width: 30, height: 50 };
     let rect_two = Rectangle { width: 10, height: 40 };
     let rect_three = Rectangle { width: 60, height: 45 };
+    let rect_square = Rectangle::square(5);
 
     println!("rect_one is {:?}", rect_one);
     println!("The area of the rectangle is {:?}", rect_one.area());
     println!("Can rect_one hold rect_two? {}", rect_one.can_hold(&rect_two));
     println!("Can rect_one hold rect_three? {}", rect_one.can_hold(&rect_three));
+    println!("rect_one is {}", rect_one);
+    println!("rect_square is {}", rect_square);
 }
 
 
@@ -1,33 +1,107 @@
 mod front_of_house {
-    mod hosting {
-        fn add_to_waitlist() {}
+    pub mod hosting {
+        pub fn add_to_waitlist() {}
 
-        fn seat_at_table() {}
+        pub fn seat_at_table() {}
     }
 
-    mod serving {
-        fn take_order() {}
+    pub mod serving {
+        use crate::{Order, OrderStatus};
 
-        fn serve_order() {}
+        pub fn take_order(mut order: Order) -> Order {
+            order.status = OrderStatus::Cooking;
+            order
+        }
 
-        fn take_payment() {}
+        pub fn serve_order(mut order: Order) -> Order {
+            order.status = OrderStatus::Served;
+            order
+        }
+
+        pub fn take_payment(mut order: Order) -> Order {
+            order.status = OrderStatus::Paid;
+            order
+        }
     }
 }
 
 mod back_of_house {
-    mod cooking {
-        fn select_ingredients() {}
+    pub mod cooking {
+        pub fn select_ingredients(dish: &str) -> Vec<String> {
+            vec![format!("{} ingredients", dish)]
+        }
 
-        fn cut_vegetables() {}
+        pub fn cut_vegetables() {}
     }
 
-    mod preparing {
-        fn prepare_dish() {}
+    pub mod preparing {
+        pub fn prepare_dish(dish: &str) -> String {
+            format!("{} (prepared)", dish)
+        }
     }
 
-    mod cleaning {
-        fn clean_dishes() {}
+    pub mod cleaning {
+        pub fn clean_dishes() {}
+
+        pub fn clean_floor() {}
+    }
+}
+
+use back_of_house::{cooking, preparing};
+use front_of_house::hosting;
+use front_of_house::serving::{serve_order, take_order, take_payment};
 
-        fn clean_floor() {}
+#[derive(Debug, PartialEq)]
+pub enum OrderStatus {
+    Waiting,
+    Cooking,
+    Served,
+    Paid,
+}
+
+#[derive(Debug)]
+pub struct Order {
+    pub table_number: u8,
+    pub dishes: Vec<String>,
+    pub total_cents: u32,
+    pub status: OrderStatus,
+}
+
+impl Order {
+    pub fn new(table_number: u8, dishes: Vec<String>, total_cents: u32) -> Self {
+        Order {
+            table_number,
+            dishes,
+            total_cents,
+            status: OrderStatus::Waiting,
+        }
     }
 }
+
+pub fn eat_at_restaurant() {
+    hosting::add_to_waitlist();
+
+    let order = Order::new(12, vec![String::from("Pasta")], 1500);
+    let mut order = take_order(order);
+
+    for dish in &order.dishes {
+        cooking::select_ingredients(dish);
+        preparing::prepare_dish(dish);
+    }
+
+    order = serve_order(order);
+    order = take_payment(order);
+
+    back_of_house::cleaning::clean_dishes();
+
+    println!(
+        "Table {} paid ${:.2}, final status: {:?}",
+        order.table_number,
+        order.total_cents as f64 / 100.0,
+        order.status
+    );
+}
+
+fn main() {
+    eat_at_restaurant();
+}
@@ -22,19 +22,37 @@ fn main() {
 
     fn build_user(email: String, username: String) -> User {
         User {
-            email: email,
-            username: username,
+            email,
+            username,
             active: true,
             sign_in_count: 1,
         }
     }
 
-    user.email = String::from("anotheremail@example.com");
+    user_one_mutable.email = String::from("anotheremail@example.com");
 
     let user_two_immutable = User {
         email: String::from("another@example.com"),
         username: String::from("anotherusername567"),
-        active: user_one_immutable.active,
-        sign_in_count: user_one_immutable.sign_in_count,
-    }
+        ..user_one_immutable
+    };
+
+    let user_three = build_user(String::from("third@example.com"), String::from("thirdusername"));
+
+    println!("user_one_mutable email is now {}", user_one_mutable.email);
+    println!(
+        "user_two_immutable active: {}, sign_in_count: {}",
+        user_two_immutable.active, user_two_immutable.sign_in_count
+    );
+    println!("user_three username is {}", user_three.username);
+
+    struct Color(i32, i32, i32);
+    struct Point(i32, i32, i32);
+
+    let _black = Color(0, 0, 0);
+    let _origin = Point(0, 0, 0);
+
+    struct AlwaysEqual;
+
+    let _subject = AlwaysEqual;
 }